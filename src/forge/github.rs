@@ -0,0 +1,220 @@
+use super::{Forge, PullRequest};
+use anyhow::{Context, Result};
+use chrono::DateTime;
+
+pub struct GitHubForge {
+    /// The remote host this instance talks to, e.g. `github.com` or a GitHub Enterprise host.
+    host: String,
+}
+
+impl GitHubForge {
+    pub fn new(host: String) -> Self {
+        Self { host }
+    }
+
+    /// REST API base URL: `api.github.com` for the public host, `<host>/api/v3` for GHE.
+    fn api_base(&self) -> String {
+        if self.host == "github.com" {
+            "https://api.github.com".to_string()
+        } else {
+            format!("https://{}/api/v3", self.host)
+        }
+    }
+
+    /// GraphQL endpoint: `api.github.com/graphql` for the public host, `<host>/api/graphql` for GHE.
+    fn graphql_url(&self) -> String {
+        if self.host == "github.com" {
+            "https://api.github.com/graphql".to_string()
+        } else {
+            format!("https://{}/api/graphql", self.host)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Forge for GitHubForge {
+    fn token_env_var(&self) -> &'static str {
+        "GITHUB_TOKEN"
+    }
+
+    fn parse_remote(&self, url: &str) -> Option<(String, String)> {
+        // Extract owner and repo from different URL formats
+        let ssh_prefix = format!("{}:", self.host);
+        let https_prefix = format!("{}/", self.host);
+        let repo_path = if url.contains(&ssh_prefix) {
+            // SSH format: git@<host>:owner/repo.git
+            url.split(&ssh_prefix).nth(1)
+        } else if url.contains(&https_prefix) {
+            // HTTPS format: https://<host>/owner/repo.git
+            url.split(&https_prefix).nth(1)
+        } else {
+            return None;
+        }?
+        .trim_end_matches(".git")
+        .to_string();
+
+        let parts: Vec<&str> = repo_path.split('/').collect();
+        if parts.len() >= 2 {
+            Some((parts[0].to_string(), parts[1].to_string()))
+        } else {
+            None
+        }
+    }
+
+    async fn authenticated_user(&self, token: &str) -> Result<String> {
+        let client = reqwest::Client::new();
+        let response = client
+            .get(format!("{}/user", self.api_base()))
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "rust-graphql-client")
+            .send()
+            .await?
+            .json::<serde_json::Value>()
+            .await?;
+
+        response["login"]
+            .as_str()
+            .context("Could not get authenticated user")
+            .map(|s| s.to_string())
+    }
+
+    async fn fetch_pull_requests(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        author: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<PullRequest>> {
+        let client = reqwest::Client::new();
+
+        let query = r#"
+        query($searchQuery: String!, $after: String) {
+          search(query: $searchQuery, type: ISSUE, first: 100, after: $after) {
+            pageInfo {
+              hasNextPage
+              endCursor
+            }
+            nodes {
+              ... on PullRequest {
+                number
+                title
+                url
+                body
+                createdAt
+                updatedAt
+                isDraft
+                state
+                merged
+                reviewDecision
+                additions
+                deletions
+                commits(last: 1) {
+                  nodes {
+                    commit {
+                      statusCheckRollup {
+                        state
+                      }
+                    }
+                  }
+                }
+                author {
+                  login
+                }
+                repository {
+                  nameWithOwner
+                }
+              }
+            }
+          }
+        }
+        "#;
+
+        let search_query = format!("type:pr repo:{}/{} author:{}", owner, repo, author);
+
+        let mut all_nodes = Vec::new();
+        let mut after: Option<String> = None;
+
+        loop {
+            let variables = serde_json::json!({
+                "searchQuery": search_query,
+                "after": after,
+            });
+
+            let response = client
+                .post(self.graphql_url())
+                .header("Authorization", format!("Bearer {}", token))
+                .header("User-Agent", "rust-graphql-client")
+                .json(&serde_json::json!({
+                    "query": query,
+                    "variables": variables,
+                }))
+                .send()
+                .await?
+                .json::<serde_json::Value>()
+                .await?;
+
+            if let Some(errors) = response.get("errors") {
+                return Err(anyhow::anyhow!(
+                    "GraphQL Error: {}",
+                    serde_json::to_string_pretty(errors)?
+                ));
+            }
+
+            let search = &response["data"]["search"];
+            let nodes = search["nodes"].as_array().context("No PRs found")?;
+            all_nodes.extend(nodes.iter().cloned());
+
+            if let Some(max) = limit {
+                if all_nodes.len() >= max {
+                    break;
+                }
+            }
+
+            let has_next_page = search["pageInfo"]["hasNextPage"].as_bool().unwrap_or(false);
+            if !has_next_page {
+                break;
+            }
+            after = search["pageInfo"]["endCursor"].as_str().map(|s| s.to_string());
+        }
+
+        if let Some(max) = limit {
+            all_nodes.truncate(max);
+        }
+
+        let prs = all_nodes
+            .iter()
+            .map(|pr| {
+                Ok(PullRequest {
+                    number: pr["number"].as_i64().context("No number")? as i32,
+                    title: pr["title"].as_str().context("No title")?.to_string(),
+                    html_url: pr["url"].as_str().context("No URL")?.to_string(),
+                    body: pr["body"].as_str().map(|s| s.to_string()),
+                    created_at: DateTime::parse_from_rfc3339(
+                        pr["createdAt"].as_str().context("No createdAt")?,
+                    )?
+                    .with_timezone(&chrono::Utc),
+                    updated_at: DateTime::parse_from_rfc3339(
+                        pr["updatedAt"].as_str().context("No updatedAt")?,
+                    )?
+                    .with_timezone(&chrono::Utc),
+                    repository_name: pr["repository"]["nameWithOwner"]
+                        .as_str()
+                        .context("No repository name")?
+                        .to_string(),
+                    state: pr["state"].as_str().context("No state")?.to_string(),
+                    is_draft: pr["isDraft"].as_bool().context("No isDraft")?,
+                    merged: pr["merged"].as_bool().context("No merged status")?,
+                    review_decision: pr["reviewDecision"].as_str().map(|s| s.to_string()),
+                    ci_status: pr["commits"]["nodes"][0]["commit"]["statusCheckRollup"]["state"]
+                        .as_str()
+                        .map(|s| s.to_string()),
+                    additions: pr["additions"].as_i64().unwrap_or(0),
+                    deletions: pr["deletions"].as_i64().unwrap_or(0),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(prs)
+    }
+}
@@ -0,0 +1,164 @@
+use super::{Forge, PullRequest};
+use anyhow::{Context, Result};
+use chrono::DateTime;
+
+pub struct GitLabForge {
+    /// The remote host this instance talks to, e.g. `gitlab.com` or a self-managed host.
+    host: String,
+}
+
+impl GitLabForge {
+    pub fn new(host: String) -> Self {
+        Self { host }
+    }
+
+    fn api_base(&self) -> String {
+        format!("https://{}/api/v4", self.host)
+    }
+}
+
+/// Map GitLab's merge request states onto the shared GitHub-flavored vocabulary
+/// (`OPEN`/`CLOSED`/`MERGED`) that `get_status_priority` and `score_pr` compare against.
+fn normalize_state(state: &str) -> String {
+    match state {
+        "opened" => "OPEN".to_string(),
+        "closed" => "CLOSED".to_string(),
+        "merged" => "MERGED".to_string(),
+        other => other.to_uppercase(),
+    }
+}
+
+#[async_trait::async_trait]
+impl Forge for GitLabForge {
+    fn token_env_var(&self) -> &'static str {
+        "GITLAB_TOKEN"
+    }
+
+    fn parse_remote(&self, url: &str) -> Option<(String, String)> {
+        // Extract owner and repo from different URL formats
+        let ssh_prefix = format!("{}:", self.host);
+        let https_prefix = format!("{}/", self.host);
+        let repo_path = if url.contains(&ssh_prefix) {
+            // SSH format: git@<host>:owner/repo.git
+            url.split(&ssh_prefix).nth(1)
+        } else if url.contains(&https_prefix) {
+            // HTTPS format: https://<host>/owner/repo.git
+            url.split(&https_prefix).nth(1)
+        } else {
+            return None;
+        }?
+        .trim_end_matches(".git")
+        .to_string();
+
+        let parts: Vec<&str> = repo_path.split('/').collect();
+        if parts.len() >= 2 {
+            Some((parts[0].to_string(), parts[1].to_string()))
+        } else {
+            None
+        }
+    }
+
+    async fn authenticated_user(&self, token: &str) -> Result<String> {
+        let client = reqwest::Client::new();
+        let response = client
+            .get(format!("{}/user", self.api_base()))
+            .header("PRIVATE-TOKEN", token)
+            .send()
+            .await?
+            .json::<serde_json::Value>()
+            .await?;
+
+        response["username"]
+            .as_str()
+            .context("Could not get authenticated user")
+            .map(|s| s.to_string())
+    }
+
+    async fn fetch_pull_requests(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        author: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<PullRequest>> {
+        let client = reqwest::Client::new();
+        let project = format!("{}/{}", owner, repo);
+        let project_id = project.replace('/', "%2F");
+
+        let mut all_prs = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response = client
+                .get(format!(
+                    "{}/projects/{}/merge_requests",
+                    self.api_base(),
+                    project_id
+                ))
+                .header("PRIVATE-TOKEN", token)
+                .query(&[
+                    ("author_username", author),
+                    ("scope", "all"),
+                    ("per_page", "100"),
+                    ("page", &page.to_string()),
+                ])
+                .send()
+                .await?
+                .json::<Vec<serde_json::Value>>()
+                .await?;
+
+            if response.is_empty() {
+                break;
+            }
+
+            for mr in &response {
+                let state = mr["state"].as_str().context("No state")?;
+                all_prs.push(PullRequest {
+                    number: mr["iid"].as_i64().context("No iid")? as i32,
+                    title: mr["title"].as_str().context("No title")?.to_string(),
+                    html_url: mr["web_url"].as_str().context("No web_url")?.to_string(),
+                    body: mr["description"].as_str().map(|s| s.to_string()),
+                    created_at: DateTime::parse_from_rfc3339(
+                        mr["created_at"].as_str().context("No created_at")?,
+                    )?
+                    .with_timezone(&chrono::Utc),
+                    updated_at: DateTime::parse_from_rfc3339(
+                        mr["updated_at"].as_str().context("No updated_at")?,
+                    )?
+                    .with_timezone(&chrono::Utc),
+                    repository_name: project.clone(),
+                    // GitLab's "opened"/"closed" map to the shared "OPEN"/"CLOSED" states so
+                    // the fzf sort (which checks "OPEN") and --rank logic (which checks
+                    // "CLOSED") classify MRs the same way as GitHub PRs.
+                    state: normalize_state(state),
+                    is_draft: mr["draft"].as_bool().unwrap_or(false),
+                    merged: state == "merged",
+                    // GitLab's merge_requests endpoint doesn't expose an aggregate
+                    // review decision the way GitHub's reviewDecision does.
+                    review_decision: None,
+                    ci_status: mr["pipeline"]["status"]
+                        .as_str()
+                        .map(|s| s.to_uppercase()),
+                    // GitLab's list endpoint only exposes a coarse "changes_count"
+                    // string (e.g. "3" or "1000+"), not a true additions/deletions split.
+                    additions: mr["changes_count"]
+                        .as_str()
+                        .and_then(|s| s.trim_end_matches('+').parse().ok())
+                        .unwrap_or(0),
+                    deletions: 0,
+                });
+
+                if let Some(max) = limit {
+                    if all_prs.len() >= max {
+                        return Ok(all_prs);
+                    }
+                }
+            }
+
+            page += 1;
+        }
+
+        Ok(all_prs)
+    }
+}
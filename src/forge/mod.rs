@@ -0,0 +1,85 @@
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use std::env;
+
+mod github;
+mod gitlab;
+
+pub use github::GitHubForge;
+pub use gitlab::GitLabForge;
+
+#[derive(Debug)]
+pub struct PullRequest {
+    pub number: i32,
+    pub title: String,
+    pub html_url: String,
+    pub body: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub repository_name: String,
+    pub state: String,
+    pub is_draft: bool,
+    pub merged: bool,
+    /// Review state (e.g. `APPROVED`, `CHANGES_REQUESTED`, `REVIEW_REQUIRED`), if known.
+    pub review_decision: Option<String>,
+    /// Rollup CI status (e.g. `SUCCESS`, `FAILURE`, `PENDING`), if known.
+    pub ci_status: Option<String>,
+    pub additions: i64,
+    pub deletions: i64,
+}
+
+/// A code-hosting backend prview knows how to talk to.
+#[async_trait::async_trait]
+pub trait Forge {
+    /// Name of the environment variable holding the access token for this forge.
+    fn token_env_var(&self) -> &'static str;
+
+    /// Extract an `(owner, repo)` pair from a git remote URL, if this forge recognizes it.
+    fn parse_remote(&self, url: &str) -> Option<(String, String)>;
+
+    /// Resolve the username the given token authenticates as.
+    async fn authenticated_user(&self, token: &str) -> Result<String>;
+
+    /// Fetch the PRs/MRs opened by `author` in `owner/repo`, newest API page first,
+    /// following every page unless `limit` caps the total number returned.
+    async fn fetch_pull_requests(
+        &self,
+        token: &str,
+        owner: &str,
+        repo: &str,
+        author: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<PullRequest>>;
+}
+
+/// Pull the host out of a git remote URL, e.g. `git@ghe.corp.com:o/r.git` or
+/// `https://ghe.corp.com/o/r.git` both yield `ghe.corp.com`.
+fn extract_remote_host(url: &str) -> Option<String> {
+    if let Some(rest) = url.strip_prefix("git@") {
+        return rest.split(':').next().map(|s| s.to_string());
+    }
+
+    let rest = url.split("://").nth(1)?;
+    let rest = rest.rsplit('@').next().unwrap_or(rest);
+    rest.split('/').next().map(|s| s.to_string())
+}
+
+/// Pick the forge implementation for a remote URL, falling back to `PRVIEW_HOST`
+/// (`github` or `gitlab`) for self-hosted instances that don't use the public hostnames.
+/// The self-hosted host itself is read straight off the remote, so GitHub Enterprise
+/// / self-managed GitLab API calls and remote parsing target the right instance.
+pub fn detect_forge(url: &str) -> Option<Box<dyn Forge>> {
+    if url.contains("github.com") {
+        return Some(Box::new(GitHubForge::new("github.com".to_string())));
+    }
+    if url.contains("gitlab.com") {
+        return Some(Box::new(GitLabForge::new("gitlab.com".to_string())));
+    }
+
+    let host = extract_remote_host(url)?;
+    match env::var("PRVIEW_HOST").ok().as_deref() {
+        Some("github") => Some(Box::new(GitHubForge::new(host))),
+        Some("gitlab") => Some(Box::new(GitLabForge::new(host))),
+        _ => None,
+    }
+}
@@ -1,10 +1,12 @@
 use anyhow::{Context, Result};
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Local, Utc};
 use clap::Parser;
 use colored::*;
+use forge::PullRequest;
 use git2::Repository;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::HashMap,
     env,
     fs::{self, File},
     io::Write,
@@ -12,6 +14,8 @@ use std::{
 };
 use tempfile::TempDir;
 
+mod forge;
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Args {
@@ -26,20 +30,66 @@ struct Args {
     /// Disable preview panel
     #[arg(long)]
     no_preview: bool,
+
+    /// Cap the total number of PRs fetched across all pages
+    #[arg(long)]
+    limit: Option<usize>,
+
+    /// Sort by review-readiness score instead of last-updated time
+    #[arg(long)]
+    rank: bool,
+
+    /// Emit an RSS feed of PR changes since the last run instead of launching fzf
+    #[arg(long)]
+    feed: bool,
+
+    /// Path to the JSON snapshot file used by --feed to detect changes
+    #[arg(long)]
+    state: Option<PathBuf>,
+
+    /// Show an absolute local-timezone timestamp alongside the relative time
+    #[arg(long)]
+    local: bool,
 }
 
-#[derive(Debug)]
-struct PullRequest {
-    number: i32,
-    title: String,
-    html_url: String,
-    body: Option<String>,
-    created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
-    repository_name: String,
-    state: String,
-    is_draft: bool,
-    merged: bool,
+// Weights used by `score_pr` to rank PRs by review-readiness. Higher score sorts first.
+const WEIGHT_AGE_PER_DAY: f64 = 1.0;
+const WEIGHT_CI_PASSING: f64 = 50.0;
+const WEIGHT_REVIEW_REQUIRED: f64 = 40.0;
+const WEIGHT_CHANGES_REQUESTED: f64 = -30.0;
+const WEIGHT_DRAFT: f64 = -60.0;
+const WEIGHT_SIZE_PER_LINE: f64 = -0.1;
+// Merged/closed PRs aren't awaiting review at all, so they must never outrank an open one.
+const WEIGHT_NOT_OPEN: f64 = -10_000.0;
+
+/// Score a PR by review-readiness: older, green, unreviewed, non-draft, small *open* PRs
+/// rank highest. Merged/closed PRs are pushed to the bottom since there's nothing left to review.
+fn score_pr(pr: &PullRequest) -> f64 {
+    if pr.merged || pr.state == "CLOSED" {
+        return WEIGHT_NOT_OPEN;
+    }
+
+    let age_days = Utc::now().signed_duration_since(pr.updated_at).num_days() as f64;
+    let mut score = age_days * WEIGHT_AGE_PER_DAY;
+
+    if pr.ci_status.as_deref() == Some("SUCCESS") {
+        score += WEIGHT_CI_PASSING;
+    }
+
+    match pr.review_decision.as_deref() {
+        Some("REVIEW_REQUIRED") => score += WEIGHT_REVIEW_REQUIRED,
+        Some("CHANGES_REQUESTED") => score += WEIGHT_CHANGES_REQUESTED,
+        _ => {}
+    }
+
+    if pr.is_draft {
+        score += WEIGHT_DRAFT;
+    }
+
+    let size = (pr.additions + pr.deletions) as f64;
+    score += size * WEIGHT_SIZE_PER_LINE;
+
+    score
 }
 
 fn get_relative_time(date: DateTime<Utc>) -> String {
@@ -64,6 +114,29 @@ fn get_relative_time(date: DateTime<Utc>) -> String {
     }
 }
 
+/// Render `date` in the machine's local offset (read at call time, not assumed to be UTC).
+fn get_local_time(date: DateTime<Utc>) -> String {
+    date.with_timezone(&Local).format("%Y-%m-%d %H:%M").to_string()
+}
+
+fn get_ci_status_column(pr: &PullRequest) -> String {
+    match pr.ci_status.as_deref() {
+        Some("SUCCESS") => "✓".green().to_string(),
+        Some("FAILURE") | Some("ERROR") | Some("FAILED") => "✗".red().to_string(),
+        Some("PENDING") | Some("EXPECTED") | Some("RUNNING") => "●".yellow().to_string(),
+        _ => "-".dimmed().to_string(),
+    }
+}
+
+fn get_review_decision_column(pr: &PullRequest) -> String {
+    match pr.review_decision.as_deref() {
+        Some("APPROVED") => "✓".green().to_string(),
+        Some("CHANGES_REQUESTED") => "✗".red().to_string(),
+        Some("REVIEW_REQUIRED") => "✎".yellow().to_string(),
+        _ => "-".dimmed().to_string(),
+    }
+}
+
 fn get_status_priority(pr: &PullRequest) -> i32 {
     if pr.is_draft || (pr.state == "OPEN" && !pr.is_draft) {
         0  // Highest priority for draft and open PRs
@@ -74,7 +147,7 @@ fn get_status_priority(pr: &PullRequest) -> i32 {
     }
 }
 
-fn get_current_repo_info() -> Result<Option<(String, String)>> {
+fn get_current_repo_remote() -> Result<Option<String>> {
     let current_dir = env::current_dir()?;
     let repo = match Repository::discover(&current_dir) {
         Ok(repo) => repo,
@@ -84,163 +157,186 @@ fn get_current_repo_info() -> Result<Option<(String, String)>> {
     let remote = repo
         .find_remote("origin")
         .context("No 'origin' remote found")?;
-    
+
     let url = remote.url().context("No URL found for origin remote")?;
-    
-    // Extract owner and repo from different URL formats
-    let repo_path = if url.contains("github.com:") {
-        // SSH format: git@github.com:owner/repo.git
-        url.split("github.com:").nth(1)
-    } else if url.contains("github.com/") {
-        // HTTPS format: https://github.com/owner/repo.git
-        url.split("github.com/").nth(1)
-    } else {
-        return Err(anyhow::anyhow!("Not a GitHub repository URL: {}", url));
+    Ok(Some(url.to_string()))
+}
+
+/// The subset of `PullRequest` worth persisting between `--feed` runs, used to
+/// detect what changed since the last snapshot.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PrSnapshot {
+    title: String,
+    html_url: String,
+    state: String,
+    is_draft: bool,
+    merged: bool,
+    review_decision: Option<String>,
+}
+
+impl From<&PullRequest> for PrSnapshot {
+    fn from(pr: &PullRequest) -> Self {
+        Self {
+            title: pr.title.clone(),
+            html_url: pr.html_url.clone(),
+            state: pr.state.clone(),
+            is_draft: pr.is_draft,
+            merged: pr.merged,
+            review_decision: pr.review_decision.clone(),
+        }
     }
-    .context("Could not parse GitHub repository URL")?
-    .trim_end_matches(".git")
-    .to_string();
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct FeedState {
+    prs: HashMap<String, PrSnapshot>,
+}
+
+fn pr_key(pr: &PullRequest) -> String {
+    format!("{}#{}", pr.repository_name, pr.number)
+}
 
-    // Split into owner and repo
-    let parts: Vec<&str> = repo_path.split('/').collect();
-    if parts.len() >= 2 {
-        Ok(Some((parts[0].to_string(), parts[1].to_string())))
+/// Describe the transition between a previously-seen snapshot and the current PR,
+/// or `None` if nothing notable changed.
+fn detect_transition(previous: Option<&PrSnapshot>, current: &PullRequest) -> Option<String> {
+    let previous = match previous {
+        None => return Some("newly opened".to_string()),
+        Some(previous) => previous,
+    };
+
+    if !previous.merged && current.merged {
+        Some("newly merged".to_string())
+    } else if previous.state != "CLOSED" && current.state == "CLOSED" && !current.merged {
+        Some("newly closed".to_string())
+    } else if previous.is_draft && !current.is_draft {
+        Some("marked ready for review".to_string())
+    } else if previous.review_decision.as_deref() != Some("REVIEW_REQUIRED")
+        && current.review_decision.as_deref() == Some("REVIEW_REQUIRED")
+    {
+        Some("new review requested".to_string())
     } else {
-        Err(anyhow::anyhow!("Invalid GitHub repository format: {}", repo_path))
+        None
     }
 }
 
-async fn fetch_pull_requests(token: &str, owner: &str, repo: &str, author: &str) -> Result<Vec<PullRequest>> {
-    let client = reqwest::Client::new();
-    
-    let query = r#"
-    query($searchQuery: String!) {
-      search(query: $searchQuery, type: ISSUE, first: 100) {
-        nodes {
-          ... on PullRequest {
-            number
-            title
-            url
-            body
-            createdAt
-            updatedAt
-            isDraft
-            state
-            merged
-            author {
-              login
-            }
-            repository {
-              nameWithOwner
-            }
-          }
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn load_feed_state(path: &Path) -> Result<FeedState> {
+    if !path.exists() {
+        return Ok(FeedState::default());
+    }
+    let data = fs::read_to_string(path)
+        .with_context(|| format!("Could not read state file {}", path.display()))?;
+    Ok(serde_json::from_str(&data).unwrap_or_default())
+}
+
+/// Persist the snapshot via a temp file + rename so a crash mid-write can't corrupt it.
+fn save_feed_state(path: &Path, state: &FeedState) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, serde_json::to_string_pretty(state)?)?;
+    fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+/// Diff `prs` against the snapshot at `state_path`, emit an RSS feed of what changed
+/// on stdout, and persist the updated snapshot.
+fn run_feed(prs: &[PullRequest], state_path: &Path) -> Result<()> {
+    let mut state = load_feed_state(state_path)?;
+    let mut changes = Vec::new();
+
+    for pr in prs {
+        let key = pr_key(pr);
+        if let Some(transition) = detect_transition(state.prs.get(&key), pr) {
+            changes.push((pr, transition));
         }
-      }
-    }
-    "#;
-
-    let search_query = format!("type:pr repo:{}/{} author:{}", owner, repo, author);
-    
-    let variables = serde_json::json!({
-        "searchQuery": search_query,
-    });
-
-    let response = client
-        .post("https://api.github.com/graphql")
-        .header("Authorization", format!("Bearer {}", token))
-        .header("User-Agent", "rust-graphql-client")
-        .json(&serde_json::json!({
-            "query": query,
-            "variables": variables,
-        }))
-        .send()
-        .await?
-        .json::<serde_json::Value>()
-        .await?;
+        state.prs.insert(key, PrSnapshot::from(pr));
+    }
 
-    if let Some(errors) = response.get("errors") {
-        return Err(anyhow::anyhow!(
-            "GraphQL Error: {}",
-            serde_json::to_string_pretty(errors)?
-        ));
-    }
-
-    let nodes = response["data"]["search"]["nodes"]
-        .as_array()
-        .context("No PRs found")?;
-
-    let prs = nodes
-        .iter()
-        .map(|pr| {
-            Ok(PullRequest {
-                number: pr["number"].as_i64().context("No number")? as i32,
-                title: pr["title"].as_str().context("No title")?.to_string(),
-                html_url: pr["url"].as_str().context("No URL")?.to_string(),
-                body: pr["body"].as_str().map(|s| s.to_string()),
-                created_at: DateTime::parse_from_rfc3339(
-                    pr["createdAt"].as_str().context("No createdAt")?,
-                )?.with_timezone(&Utc),
-                updated_at: DateTime::parse_from_rfc3339(
-                    pr["updatedAt"].as_str().context("No updatedAt")?,
-                )?.with_timezone(&Utc),
-                repository_name: pr["repository"]["nameWithOwner"].as_str().context("No repository name")?.to_string(),
-                state: pr["state"].as_str().context("No state")?.to_string(),
-                is_draft: pr["isDraft"].as_bool().context("No isDraft")?,
-                merged: pr["merged"].as_bool().context("No merged status")?,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
-
-    Ok(prs)
+    println!(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    println!(r#"<rss version="2.0"><channel>"#);
+    println!("<title>prview activity</title>");
+    println!("<description>PR/MR changes detected by prview</description>");
+    for (pr, transition) in &changes {
+        println!("<item>");
+        println!("<title>{}</title>", xml_escape(&pr.title));
+        println!("<link>{}</link>", xml_escape(&pr.html_url));
+        println!("<guid>{}</guid>", xml_escape(&pr.html_url));
+        println!("<description>{}</description>", xml_escape(transition));
+        println!("</item>");
+    }
+    println!("</channel></rss>");
+
+    save_feed_state(state_path, &state)
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Args::parse();
 
-    let github_token = env::var("GITHUB_TOKEN")
-        .context("Missing GITHUB_TOKEN in environment variables")?;
+    let remote_url = get_current_repo_remote()?
+        .context("Not in a git repository or no 'origin' remote found")?;
+
+    let current_forge = forge::detect_forge(&remote_url).context(
+        "Could not determine forge (GitHub/GitLab) from remote; set PRVIEW_HOST=github or PRVIEW_HOST=gitlab for self-hosted instances",
+    )?;
+
+    let repo_info = current_forge
+        .parse_remote(&remote_url)
+        .context("Could not parse owner/repo from remote URL")?;
+
+    let token_env_var = current_forge.token_env_var();
+    let token = env::var(token_env_var)
+        .with_context(|| format!("Missing {} in environment variables", token_env_var))?;
 
-    let client = reqwest::Client::new();
     let author = if let Some(author) = args.author {
         author
     } else {
-        let response = client
-            .get("https://api.github.com/user")
-            .header("Authorization", format!("Bearer {}", github_token))
-            .header("User-Agent", "rust-graphql-client")
-            .send()
-            .await?
-            .json::<serde_json::Value>()
-            .await?;
-        
-        response["login"]
-            .as_str()
-            .context("Could not get authenticated user")?
-            .to_string()
+        current_forge.authenticated_user(&token).await?
     };
 
-    let repo_info = get_current_repo_info()?
-        .context("Not in a git repository or not a GitHub repository")?;
+    let mut all_prs = current_forge
+        .fetch_pull_requests(&token, &repo_info.0, &repo_info.1, &author, args.limit)
+        .await?;
 
-    let mut all_prs = fetch_pull_requests(&github_token, &repo_info.0, &repo_info.1, &author).await?;
+    if args.feed {
+        let state_path = args
+            .state
+            .as_deref()
+            .context("--feed requires --state <file>")?;
+        return run_feed(&all_prs, state_path);
+    }
 
     if all_prs.is_empty() {
         println!("No pull requests found.");
         return Ok(());
     }
 
-    // Sort items by update time first, then by status priority
-    all_prs.sort_by(|a, b| {
-        let date_cmp = b.updated_at.cmp(&a.updated_at);  // Most recent first
-        if date_cmp == std::cmp::Ordering::Equal {
-            let pa = get_status_priority(a);
-            let pb = get_status_priority(b);
-            pa.cmp(&pb)
-        } else {
-            date_cmp
-        }
-    });
+    if args.rank {
+        // Sort by review-readiness score, highest first
+        all_prs.sort_by(|a, b| {
+            score_pr(b)
+                .partial_cmp(&score_pr(a))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    } else {
+        // Sort items by update time first, then by status priority
+        all_prs.sort_by(|a, b| {
+            let date_cmp = b.updated_at.cmp(&a.updated_at); // Most recent first
+            if date_cmp == std::cmp::Ordering::Equal {
+                let pa = get_status_priority(a);
+                let pb = get_status_priority(b);
+                pa.cmp(&pb)
+            } else {
+                date_cmp
+            }
+        });
+    }
 
     // Create temporary directory
     let temp_dir = TempDir::new()?;
@@ -262,6 +358,8 @@ async fn main() -> Result<()> {
         };
 
         let title_colored = pr.title.blue().to_string();
+        let ci_colored = get_ci_status_column(pr);
+        let review_colored = get_review_decision_column(pr);
 
         // Create PR body file
         let safe_repo_name = pr.repository_name.replace('/', "_");
@@ -276,26 +374,29 @@ async fn main() -> Result<()> {
 
         pr_map.push((file_path.to_string_lossy().to_string(), pr));
 
-        // Only include repository name in the display if --all flag is used
-        let line = if args.all {
-            format!(
-                "{}\t{}\t{}\t{}\t{}",
-                file_path.to_string_lossy(),
-                relative_time,
-                status_colored,
-                title_colored,
-                pr.repository_name
-            )
+        let score_column = if args.rank {
+            Some(format!("{:.1}", score_pr(pr)).cyan().to_string())
         } else {
-            format!(
-                "{}\t{}\t{}\t{}",
-                file_path.to_string_lossy(),
-                relative_time,
-                status_colored,
-                title_colored,
-            )
+            None
         };
-        fzf_lines.push(line);
+
+        // Leading hidden field is the body-preview file path; everything else is shown,
+        // with the score column only present in --rank mode and repository name in --all mode.
+        let mut fields = vec![file_path.to_string_lossy().to_string()];
+        fields.extend(score_column);
+        fields.push(relative_time);
+        if args.local {
+            fields.push(get_local_time(pr.updated_at));
+        }
+        fields.push(status_colored);
+        fields.push(ci_colored);
+        fields.push(review_colored);
+        fields.push(title_colored);
+        if args.all {
+            fields.push(pr.repository_name.clone());
+        }
+
+        fzf_lines.push(fields.join("\t"));
     }
 
     let fzf_input = fzf_lines.join("\n");
@@ -311,19 +412,21 @@ async fn main() -> Result<()> {
         "--preview 'bat --color=always --line-range :500 {1} | sed \"1d\"'"
     };
 
-    let fzf_cmd = if args.all {
-        format!(
-            "fzf --ansi --delimiter='\t' --with-nth=2,3,4,5 {} < {}",
-            preview_cmd,
-            input_file.path().to_string_lossy()
-        )
-    } else {
-        format!(
-            "fzf --ansi --delimiter='\t' --with-nth=2,3,4 {} < {}",
-            preview_cmd,
-            input_file.path().to_string_lossy()
-        )
-    };
+    // Visible columns: [score?] relative_time [local_time?] status ci review title [repository_name?],
+    // preceded by the hidden file-path field at index 1.
+    let visible_field_count =
+        5 + args.rank as usize + args.all as usize + args.local as usize;
+    let with_nth = (2..=visible_field_count + 1)
+        .map(|i| i.to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    let fzf_cmd = format!(
+        "fzf --ansi --delimiter='\t' --with-nth={} {} < {}",
+        with_nth,
+        preview_cmd,
+        input_file.path().to_string_lossy()
+    );
 
     let output = duct::cmd!("sh", "-c", &fzf_cmd)
         .stdin_null()
@@ -347,3 +450,143 @@ async fn main() -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn test_pr(
+        days_old: i64,
+        merged: bool,
+        state: &str,
+        is_draft: bool,
+        ci_status: Option<&str>,
+        review_decision: Option<&str>,
+        lines_changed: i64,
+    ) -> PullRequest {
+        PullRequest {
+            number: 1,
+            title: "test".to_string(),
+            html_url: "https://example.com/owner/repo/pull/1".to_string(),
+            body: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now() - Duration::days(days_old),
+            repository_name: "owner/repo".to_string(),
+            state: state.to_string(),
+            is_draft,
+            merged,
+            review_decision: review_decision.map(|s| s.to_string()),
+            ci_status: ci_status.map(|s| s.to_string()),
+            additions: lines_changed,
+            deletions: 0,
+        }
+    }
+
+    #[test]
+    fn merged_prs_never_outrank_open_prs() {
+        let ancient_merged = test_pr(400, true, "MERGED", false, Some("SUCCESS"), None, 5);
+        let fresh_open = test_pr(1, false, "OPEN", false, Some("SUCCESS"), Some("REVIEW_REQUIRED"), 5);
+        assert!(score_pr(&fresh_open) > score_pr(&ancient_merged));
+    }
+
+    #[test]
+    fn closed_prs_score_lower_than_open_prs() {
+        let closed = test_pr(5, false, "CLOSED", false, None, None, 0);
+        let open = test_pr(0, false, "OPEN", true, None, None, 1000);
+        assert!(score_pr(&open) > score_pr(&closed));
+    }
+
+    #[test]
+    fn older_open_prs_outrank_newer_ones_all_else_equal() {
+        let older = test_pr(10, false, "OPEN", false, Some("SUCCESS"), None, 10);
+        let newer = test_pr(1, false, "OPEN", false, Some("SUCCESS"), None, 10);
+        assert!(score_pr(&older) > score_pr(&newer));
+    }
+
+    #[test]
+    fn draft_prs_score_lower_than_ready_prs() {
+        let draft = test_pr(5, false, "OPEN", true, Some("SUCCESS"), None, 10);
+        let ready = test_pr(5, false, "OPEN", false, Some("SUCCESS"), None, 10);
+        assert!(score_pr(&ready) > score_pr(&draft));
+    }
+
+    #[test]
+    fn larger_diffs_score_lower_than_smaller_ones() {
+        let large = test_pr(5, false, "OPEN", false, Some("SUCCESS"), None, 2000);
+        let small = test_pr(5, false, "OPEN", false, Some("SUCCESS"), None, 10);
+        assert!(score_pr(&small) > score_pr(&large));
+    }
+
+    fn test_snapshot(state: &str, is_draft: bool, merged: bool, review_decision: Option<&str>) -> PrSnapshot {
+        PrSnapshot {
+            title: "test".to_string(),
+            html_url: "https://example.com/owner/repo/pull/1".to_string(),
+            state: state.to_string(),
+            is_draft,
+            merged,
+            review_decision: review_decision.map(|s| s.to_string()),
+        }
+    }
+
+    #[test]
+    fn unseen_pr_is_newly_opened() {
+        let pr = test_pr(0, false, "OPEN", false, None, None, 0);
+        assert_eq!(detect_transition(None, &pr), Some("newly opened".to_string()));
+    }
+
+    #[test]
+    fn merge_transition_is_detected() {
+        let previous = test_snapshot("OPEN", false, false, None);
+        let pr = test_pr(0, true, "MERGED", false, None, None, 0);
+        assert_eq!(
+            detect_transition(Some(&previous), &pr),
+            Some("newly merged".to_string())
+        );
+    }
+
+    #[test]
+    fn close_without_merge_transition_is_detected() {
+        let previous = test_snapshot("OPEN", false, false, None);
+        let pr = test_pr(0, false, "CLOSED", false, None, None, 0);
+        assert_eq!(
+            detect_transition(Some(&previous), &pr),
+            Some("newly closed".to_string())
+        );
+    }
+
+    #[test]
+    fn draft_to_ready_transition_is_detected() {
+        let previous = test_snapshot("OPEN", true, false, None);
+        let pr = test_pr(0, false, "OPEN", false, None, None, 0);
+        assert_eq!(
+            detect_transition(Some(&previous), &pr),
+            Some("marked ready for review".to_string())
+        );
+    }
+
+    #[test]
+    fn new_review_request_transition_is_detected() {
+        let previous = test_snapshot("OPEN", false, false, None);
+        let pr = test_pr(0, false, "OPEN", false, None, Some("REVIEW_REQUIRED"), 0);
+        assert_eq!(
+            detect_transition(Some(&previous), &pr),
+            Some("new review requested".to_string())
+        );
+    }
+
+    #[test]
+    fn unchanged_pr_has_no_transition() {
+        let previous = test_snapshot("OPEN", false, false, Some("APPROVED"));
+        let pr = test_pr(0, false, "OPEN", false, None, Some("APPROVED"), 0);
+        assert_eq!(detect_transition(Some(&previous), &pr), None);
+    }
+
+    #[test]
+    fn xml_escape_handles_reserved_characters() {
+        assert_eq!(
+            xml_escape(r#"<Fix> "quotes" & 'apostrophes'"#),
+            "&lt;Fix&gt; &quot;quotes&quot; &amp; &apos;apostrophes&apos;"
+        );
+    }
+}